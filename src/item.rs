@@ -0,0 +1,55 @@
+/// The items shown in the list, and the identity used to track them across updates.
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Anything that can be tracked by a stable identity, independent of its
+/// current position in a `List`.
+///
+/// `List` uses this to remember per-item state (such as which items are
+/// marked) across calls to `update`, where the set and order of visible
+/// items can change completely.
+pub trait Identifiable {
+    fn id(&self) -> usize;
+}
+
+/// # Item
+///
+/// `Item` pairs a searchable `name` with the caller's `item`, plus a stable
+/// `id` used by `List` to track state (such as marks) for this entry
+/// regardless of where it ends up after filtering or sorting.
+#[derive(Clone)]
+pub struct Item<T>
+where
+    T: Clone,
+{
+    pub name: String,
+    pub item: Option<T>,
+    /// Char offsets into `name` that the active query matched, populated by a
+    /// `Matcher` so the renderer can highlight them. Empty until scored.
+    pub match_positions: Vec<usize>,
+    id: usize,
+}
+
+impl<T> Item<T>
+where
+    T: Clone,
+{
+    pub fn new(name: String, item: T) -> Self {
+        Item {
+            name,
+            item: Some(item),
+            match_positions: Vec::new(),
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+}
+
+impl<T> Identifiable for Item<T>
+where
+    T: Clone,
+{
+    fn id(&self) -> usize {
+        self.id
+    }
+}
@@ -0,0 +1,71 @@
+//! A small terminal fuzzy finder, in the spirit of fzf, that you can embed in your own
+//! CLI: hand it a `Vec<Item<T>>` and get back the `T` the user picked.
+
+pub mod item;
+pub mod list;
+pub mod matcher;
+mod ui;
+
+use std::sync::mpsc::Receiver;
+
+use anyhow::Result;
+
+use item::Item;
+use matcher::{FuzzyMatcher, Matcher};
+
+pub struct FuzzyFinder;
+
+impl FuzzyFinder {
+    /// Runs the finder over `items`, showing up to `lines_to_show` rows at a time, scoring
+    /// candidates with the default fuzzy `Matcher`.
+    ///
+    /// Returns the item the user selected, or `None` if they cancelled (Esc/Ctrl-C).
+    pub fn find<T: Clone>(items: Vec<Item<T>>, lines_to_show: i8) -> Result<Option<T>> {
+        Self::find_with(items, lines_to_show, &FuzzyMatcher)
+    }
+
+    /// Like `find`, but scores candidates with `matcher` instead of the default fuzzy
+    /// scorer. Use this to swap in a substring, prefix, or other custom `Matcher`.
+    pub fn find_with<T: Clone>(
+        items: Vec<Item<T>>,
+        lines_to_show: i8,
+        matcher: &dyn Matcher,
+    ) -> Result<Option<T>> {
+        ui::run(items, lines_to_show, matcher)
+    }
+
+    /// Like `find`, but lets the user mark any number of items (Tab) before confirming
+    /// (Enter), returning all of them. If the user confirms without marking anything,
+    /// the item currently selected is returned on its own.
+    pub fn find_multi<T: Clone>(items: Vec<Item<T>>, lines_to_show: i8) -> Result<Vec<T>> {
+        Self::find_multi_with(items, lines_to_show, &FuzzyMatcher)
+    }
+
+    /// Like `find_multi`, but scores candidates with `matcher` instead of the default
+    /// fuzzy scorer.
+    pub fn find_multi_with<T: Clone>(
+        items: Vec<Item<T>>,
+        lines_to_show: i8,
+        matcher: &dyn Matcher,
+    ) -> Result<Vec<T>> {
+        ui::run_multi(items, lines_to_show, matcher)
+    }
+
+    /// Like `find`, but accepts items incrementally over `rx` instead of requiring the
+    /// full collection up front: the list renders and stays searchable while a caller
+    /// loads items on a background thread, showing a loading indicator until `rx` is
+    /// closed.
+    pub fn find_streaming<T: Clone>(rx: Receiver<Item<T>>, lines_to_show: i8) -> Result<Option<T>> {
+        Self::find_streaming_with(rx, lines_to_show, &FuzzyMatcher)
+    }
+
+    /// Like `find_streaming`, but scores candidates with `matcher` instead of the
+    /// default fuzzy scorer.
+    pub fn find_streaming_with<T: Clone>(
+        rx: Receiver<Item<T>>,
+        lines_to_show: i8,
+        matcher: &dyn Matcher,
+    ) -> Result<Option<T>> {
+        ui::run_streaming(rx, lines_to_show, matcher)
+    }
+}
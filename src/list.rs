@@ -1,5 +1,16 @@
 /// The list and events for handling movement within the list. No UI.
-use std::collections::VecDeque;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+use crate::item::Identifiable;
+
+/// Upper bound on how many scored candidates `update_from_scored` retains. `List` needs
+/// more than the on-screen `capacity` so `page_up`/`page_down`/`select_first`/
+/// `select_last` can scroll through more than a single screen, but retaining literally
+/// every candidate would mean sorting the whole pool every keystroke — the O(n log n)
+/// cost the bounded min-heap below exists to avoid. This is a generous multiple of a
+/// typical screen, comfortably covering realistic paging depth.
+const MAX_RETAINED_MATCHES: usize = 4096;
 
 /// # List
 ///
@@ -18,6 +29,10 @@ use std::collections::VecDeque;
 /// - 6
 ///
 /// Maintain the INVARIANT that `selected` can only be empty if there are no elements in the list.
+///
+/// `List` also supports multi-select: items whose `T` implements `Identifiable` can be
+/// marked via `toggle_mark`, and marks are tracked against item identity so they survive
+/// `update` even when the filtered view is rebuilt from scratch.
 pub struct List<T>
 where
     T: Clone,
@@ -26,6 +41,25 @@ where
     above: VecDeque<T>,
     selected: Option<T>,
     below: VecDeque<T>,
+    /// Items marked in multi-select mode, keyed by id (see `Identifiable`) rather than
+    /// visible position. Storing the values themselves (not just their ids) means a mark
+    /// survives the marked item scrolling out of, or being filtered out of, the
+    /// `capacity`-bounded visible window.
+    marked: HashMap<usize, T>,
+    /// Insertion order of `marked`'s keys, so `marked()` returns items in the order the
+    /// user marked them rather than whatever order a `HashMap` happens to iterate in.
+    marked_order: Vec<usize>,
+    /// The full result set from the last `update`/`update_from_scored` call, in order —
+    /// not just the `capacity`-bounded slice materialised into `above`/`selected`/`below`.
+    /// After `update_from_scored` this is at most `MAX_RETAINED_MATCHES` items; lower
+    /// scoring candidates beyond that are dropped and cannot be paged to.
+    /// Lets `page_up`/`page_down`/`select_first`/`select_last` scroll the viewport past a
+    /// single screen instead of being confined to whatever's currently visible.
+    matches: Vec<T>,
+    /// Index into `matches` of the currently selected item.
+    selected_absolute: usize,
+    /// Index into `matches` of the first row of the visible window.
+    window_start: usize,
 }
 
 impl<T> List<T>
@@ -38,6 +72,11 @@ where
             above: VecDeque::new(),
             selected: None,
             below: VecDeque::new(),
+            marked: HashMap::new(),
+            marked_order: Vec::new(),
+            matches: Vec::new(),
+            selected_absolute: 0,
+            window_start: 0,
         }
     }
 
@@ -84,6 +123,7 @@ where
             if let Some(selected) = self.selected.take() {
                 self.below.push_front(selected);
                 self.selected = Some(item_above);
+                self.selected_absolute = self.selected_absolute.saturating_sub(1);
             } else {
                 unreachable!("the invariant has been violated")
             }
@@ -95,50 +135,218 @@ where
             if let Some(selected) = self.selected.take() {
                 self.above.push_back(selected);
                 self.selected = Some(item_below);
+                self.selected_absolute =
+                    (self.selected_absolute + 1).min(self.matches.len().saturating_sub(1));
             } else {
                 unreachable!("the invariant has been violated")
             }
         }
     }
 
+    /// Rebuilds `above`/`selected`/`below` from `self.matches`, selecting the item at
+    /// absolute index `target` and scrolling the window just enough to bring it into
+    /// view. Always lays the window out in the literal order of `self.matches`; `update`
+    /// delegates here too, so keystroke-driven refreshes and paging never disagree on
+    /// which row is "top".
+    fn set_window(&mut self, target: usize) {
+        let target = target.min(self.matches.len().saturating_sub(1));
+
+        if target < self.window_start {
+            self.window_start = target;
+        } else if target >= self.window_start + self.capacity {
+            self.window_start = target + 1 - self.capacity;
+        }
+        let max_start = self.matches.len().saturating_sub(self.capacity);
+        self.window_start = self.window_start.min(max_start);
+
+        let window_end = (self.window_start + self.capacity).min(self.matches.len());
+        let window = &self.matches[self.window_start..window_end];
+        let row = target - self.window_start;
+
+        self.above = window[..row].iter().cloned().collect();
+        self.selected = Some(window[row].clone());
+        self.below = window[row + 1..].iter().cloned().collect();
+        self.selected_absolute = target;
+    }
+
+    /// Moves the selection up by a full screen (`capacity` rows) over the entire
+    /// filtered result set, scrolling the viewport as needed, and stopping at the top.
+    pub fn page_up(&mut self) {
+        if self.matches.is_empty() {
+            return;
+        }
+        let target = self.selected_absolute.saturating_sub(self.capacity);
+        self.set_window(target);
+    }
+
+    /// Moves the selection down by a full screen (`capacity` rows) over the entire
+    /// filtered result set, scrolling the viewport as needed, and stopping at the bottom.
+    pub fn page_down(&mut self) {
+        if self.matches.is_empty() {
+            return;
+        }
+        let target = (self.selected_absolute + self.capacity).min(self.matches.len() - 1);
+        self.set_window(target);
+    }
+
+    /// Jumps the selection to the first item of the entire filtered result set,
+    /// scrolling the viewport back to the top if it had scrolled further down.
+    pub fn select_first(&mut self) {
+        if !self.matches.is_empty() {
+            self.set_window(0);
+        }
+    }
+
+    /// Jumps the selection to the last item of the entire filtered result set,
+    /// scrolling the viewport so the final item becomes the selected bottom row.
+    pub fn select_last(&mut self) {
+        if !self.matches.is_empty() {
+            self.set_window(self.matches.len() - 1);
+        }
+    }
+
     /// Takes the current matches and updates the visible contents.
     ///
-    /// The input matches are assumed to be sorted in descending order of score.
+    /// The input matches are assumed to be sorted in descending order of score, and the
+    /// full slice is retained (see `matches`) so `page_up`/`page_down`/`select_first`/
+    /// `select_last` can scroll through the entire result set, not just the
+    /// `capacity`-bounded window materialised below. The window is rebuilt through
+    /// `set_window`, the same path paging uses, so both agree on which row is "top" —
+    /// previously `update` and `set_window` disagreed, and the display would visibly
+    /// flip orientation the first time the user paged.
+    ///
+    /// Tries to keep the selection on the same screen row it was on before, so
+    /// continuing to type doesn't make the selection jump around; selects the first
+    /// match if the list was empty, and the last match if there are now fewer matches
+    /// than that row.
     pub fn update(&mut self, matches: &[T]) {
         log::info!("Updating view with {} match(es)", matches.len());
-        let is_empty = self.is_empty();
-        let selected_len = self.selected.iter().count();
-        let below_len = self.below.len();
-        let above_len = self.capacity - selected_len - below_len;
-        assert_eq!(above_len + selected_len + below_len, self.capacity);
+        let previous_row = if self.is_empty() { 0 } else { self.len_above() };
 
         self.above.clear();
         self.below.clear();
         self.selected = None;
+        self.matches = matches.to_vec();
+        self.window_start = 0;
+        self.selected_absolute = 0;
 
-        // take the highest scoring items
-        let iter = matches.iter().take(self.capacity as usize).cloned();
-
-        if is_empty {
-            // extend above so the bottom item gets selected if the List was initially empty
-            self.above.extend(iter.rev());
-        } else {
-            // otherwise fill up from below
-            self.below.extend(iter.clone().take(below_len).rev());
-            self.selected = iter.clone().nth(below_len);
-            self.above.extend(iter.skip(below_len + 1).rev());
+        if self.matches.is_empty() {
+            return;
         }
 
-        // ensure invariant
-        if self.selected.is_none() {
-            // select the top-most item by default
-            self.selected = self.below.pop_front().or_else(|| self.above.pop_back());
-        }
+        let target = previous_row.min(self.matches.len() - 1);
+        self.set_window(target);
     }
 
     pub fn get_selected(&self) -> &T {
         self.selected.as_ref().unwrap()
     }
+
+    /// Like `update`, but computes the top `MAX_RETAINED_MATCHES` scored items itself
+    /// with a bounded min-heap in O(n log k), rather than requiring the caller to sort
+    /// every candidate up front. Ties break on original index: earlier candidates win.
+    ///
+    /// The bound is `MAX_RETAINED_MATCHES`, not `capacity`: `List` needs more than one
+    /// screen's worth of matches so `page_up`/`page_down`/`select_first`/`select_last`
+    /// have a full result set to scroll through, not just whatever the screen shows.
+    /// Candidates ranked below that bound are dropped, so `select_last` and paging stop
+    /// at the last retained match.
+    pub fn update_from_scored(&mut self, scored: impl Iterator<Item = (i64, T)>) {
+        let limit = MAX_RETAINED_MATCHES;
+        let mut heap: BinaryHeap<std::cmp::Reverse<ScoredEntry<T>>> =
+            BinaryHeap::with_capacity(limit);
+
+        for (index, (score, item)) in scored.enumerate() {
+            let entry = ScoredEntry { score, index, item };
+            if heap.len() < limit {
+                heap.push(std::cmp::Reverse(entry));
+            } else if let Some(std::cmp::Reverse(min)) = heap.peek() {
+                if entry.score > min.score {
+                    heap.pop();
+                    heap.push(std::cmp::Reverse(entry));
+                }
+            }
+        }
+
+        let mut top: Vec<ScoredEntry<T>> =
+            heap.into_iter().map(|std::cmp::Reverse(entry)| entry).collect();
+        top.sort_by(|a, b| b.score.cmp(&a.score).then(a.index.cmp(&b.index)));
+
+        let matches: Vec<T> = top.into_iter().map(|entry| entry.item).collect();
+        self.update(&matches);
+    }
+}
+
+/// A candidate paired with its score and original position, used by
+/// `List::update_from_scored` to find the top-K without sorting everything. Ordered by
+/// score only (with ties broken by index), regardless of what `T` is.
+struct ScoredEntry<T> {
+    score: i64,
+    index: usize,
+    item: T,
+}
+
+impl<T> PartialEq for ScoredEntry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.score == other.score && self.index == other.index
+    }
+}
+
+impl<T> Eq for ScoredEntry<T> {}
+
+impl<T> PartialOrd for ScoredEntry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for ScoredEntry<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Lower score is "smaller" (evicted first); for ties, the later candidate is
+        // "smaller" so the earlier one survives.
+        self.score.cmp(&other.score).then(other.index.cmp(&self.index))
+    }
+}
+
+impl<T> List<T>
+where
+    T: Clone + Identifiable,
+{
+    /// Toggles the marked state of the currently selected item.
+    pub fn toggle_mark(&mut self) {
+        if let Some(selected) = &self.selected {
+            let id = selected.id();
+            if self.marked.remove(&id).is_none() {
+                self.marked.insert(id, selected.clone());
+                self.marked_order.push(id);
+            } else {
+                self.marked_order.retain(|&marked_id| marked_id != id);
+            }
+        }
+    }
+
+    pub fn is_marked(&self, item: &T) -> bool {
+        self.marked.contains_key(&item.id())
+    }
+
+    /// Every currently marked item, regardless of whether it's still part of the
+    /// visible window (or even still a match for the current query) — marks are kept
+    /// by identity, not derived from what's on screen. Returned in the order they were
+    /// marked, not `HashMap` iteration order, so a user marking items top-to-bottom
+    /// gets them back in that same order.
+    pub fn marked(&self) -> impl Iterator<Item = T> + '_ {
+        self.marked_order.iter().map(|id| self.marked[id].clone())
+    }
+
+    /// Like [`List::tagged_iter`] but also reports whether each item is marked,
+    /// so a multi-select UI can render a marker glyph alongside the selection caret.
+    pub fn tagged_marked_iter<'a>(&'a self) -> Box<dyn Iterator<Item = (bool, bool, T)> + 'a> {
+        let selected_index = self.len_above();
+        Box::new(self.items().enumerate().map(move |(index, item)| {
+            let marked = self.is_marked(&item);
+            (index == selected_index, marked, item)
+        }))
+    }
 }
 
 #[cfg(test)]
@@ -201,26 +409,49 @@ mod tests {
         // WHEN
         setup.view.update(&setup.items);
 
-        // THEN
+        // THEN the best match ("A") is selected, at the top, with nothing above it.
         assert_eq!(setup.view.len(), 8);
-        assert_eq!(setup.view.len_above(), 7); // 0-indexed
+        assert_eq!(setup.view.len_above(), 0);
         assert_eq!(setup.view.get_selected().item.as_ref().unwrap().name, "A")
     }
 
+    #[test]
+    fn test_down() {
+        // GIVEN
+        let mut setup = Setup::new(8);
+        setup.view.update(&setup.items);
+
+        // WHEN
+        setup.view.down(); // B
+        setup.view.down(); // C
+        setup.view.down(); // D
+
+        // THEN
+        assert_eq!(setup.view.len(), 8);
+        assert_eq!(setup.view.len_above(), 3);
+        assert_eq!(setup.view.get_selected().item.as_ref().unwrap().name, "D");
+    }
+
     #[test]
     fn test_up() {
         // GIVEN
         let mut setup = Setup::new(8);
         setup.view.update(&setup.items);
+        setup.view.down(); // B
+        setup.view.down(); // C
+        setup.view.down(); // D
+        setup.view.down(); // E
+        setup.view.down(); // F
 
         // WHEN
-        setup.view.up(); // 6
-        setup.view.up(); // 5
-        setup.view.up(); // 4
+        setup.view.up(); // E
+        setup.view.up(); // D
+        setup.view.up(); // C
 
         // THEN
         assert_eq!(setup.view.len(), 8);
-        assert_eq!(setup.view.len_above(), 4);
+        assert_eq!(setup.view.len_above(), 2);
+        assert_eq!(setup.view.get_selected().item.as_ref().unwrap().name, "C");
     }
 
     #[test]
@@ -228,31 +459,26 @@ mod tests {
         // GIVEN
         let mut setup = Setup::new(8);
         setup.view.update(&setup.items);
-        assert!(setup.items.len() > 0);
+        assert!(!setup.items.is_empty());
         assert_eq!(
             setup.view.len(),
             setup.view.capacity().min(setup.items.len())
         );
+        for _ in 0..7 {
+            setup.view.down(); // walk to the bottom of the window (H)
+        }
+        assert_eq!(setup.view.len_above(), 7);
 
         // WHEN
         // More than lines_to_show
-        setup.view.up();
-        setup.view.up();
-        setup.view.up();
-        setup.view.up();
-        setup.view.up();
-        setup.view.up();
-        setup.view.up();
-        setup.view.up();
-        setup.view.up();
-        setup.view.up();
-        setup.view.up();
-        setup.view.up();
-        setup.view.up();
+        for _ in 0..13 {
+            setup.view.up();
+        }
 
-        // THEN
+        // THEN the selection stops at the top rather than underflowing.
         assert_eq!(setup.view.len(), 8);
         assert_eq!(setup.view.len_above(), 0);
+        assert_eq!(setup.view.get_selected().item.as_ref().unwrap().name, "A");
     }
 
     #[test]
@@ -260,46 +486,286 @@ mod tests {
         // GIVEN
         let mut setup = Setup::new(8);
         setup.view.update(&setup.items);
+        for _ in 0..7 {
+            setup.view.down(); // walk to the bottom of the window (H)
+        }
+        assert_eq!(setup.view.len_above(), 7);
 
-        // WHEN
-        setup.view.down(); // 7
+        // WHEN going past the bottom of the visible window
+        setup.view.down();
 
-        // THEN
+        // THEN the selection stays put rather than violating the invariant.
         assert_eq!(setup.view.len(), 8);
         assert_eq!(setup.view.len_above(), 7);
+        assert_eq!(setup.view.get_selected().item.as_ref().unwrap().name, "H");
     }
 
     #[test]
-    fn test_down() {
+    fn test_few() {
         // GIVEN
         let mut setup = Setup::new(8);
-        setup.view.update(&setup.items);
 
         // WHEN
+        setup.view.update(&setup.few_items);
         setup.view.up(); // 6
         setup.view.up(); // 5
-        setup.view.up(); // 4
-        setup.view.down(); // 5
+        setup.view.up(); // 5
+        setup.view.up(); // 5
 
         // THEN
-        assert_eq!(setup.view.len(), 8);
-        assert_eq!(setup.view.len_above(), 5);
+        assert_eq!(setup.view.len(), 3);
+        assert_eq!(setup.view.len_above(), 0);
     }
 
     #[test]
-    fn test_few() {
+    fn test_toggle_mark() {
         // GIVEN
         let mut setup = Setup::new(8);
+        setup.view.update(&setup.items);
 
         // WHEN
-        setup.view.update(&setup.few_items);
-        setup.view.up(); // 6
-        setup.view.up(); // 5
-        setup.view.up(); // 5
-        setup.view.up(); // 5
+        let selected = setup.view.get_selected().clone();
+        setup.view.toggle_mark();
 
         // THEN
-        assert_eq!(setup.view.len(), 3);
+        assert!(setup.view.is_marked(&selected));
+
+        // WHEN toggled again
+        setup.view.toggle_mark();
+
+        // THEN
+        assert!(!setup.view.is_marked(&selected));
+    }
+
+    #[test]
+    fn test_marked_returns_items_in_mark_order() {
+        // GIVEN
+        let mut setup = Setup::new(8);
+        setup.view.update(&setup.items);
+
+        // WHEN marking D, then A, then C (not the order they appear in the list)
+        setup.view.down(); // B
+        setup.view.down(); // C
+        setup.view.down(); // D
+        setup.view.toggle_mark();
+        setup.view.select_first(); // A
+        setup.view.toggle_mark();
+        setup.view.down(); // B
+        setup.view.down(); // C
+        setup.view.toggle_mark();
+
+        // THEN `marked` returns them in the order they were marked, not some other order.
+        let marked_names: Vec<String> = setup
+            .view
+            .marked()
+            .map(|item| item.item.as_ref().unwrap().name.clone())
+            .collect();
+        assert_eq!(marked_names, vec!["D", "A", "C"]);
+    }
+
+    #[test]
+    fn test_marks_survive_update() {
+        // GIVEN
+        let mut setup = Setup::new(8);
+        setup.view.update(&setup.items);
+        let marked = setup.view.get_selected().clone();
+        setup.view.toggle_mark();
+        setup.view.down();
+        setup.view.down();
+
+        // WHEN the filtered view is rebuilt from scratch
+        setup.view.update(&setup.few_items);
+
+        // THEN the original item's mark is still remembered, and still returned by
+        // `marked`, even though it's no longer part of the visible view.
+        assert!(setup.view.is_marked(&marked));
+        let marked_names: Vec<String> = setup
+            .view
+            .marked()
+            .map(|item| item.item.as_ref().unwrap().name.clone())
+            .collect();
+        assert_eq!(marked_names, vec![marked.item.as_ref().unwrap().name.clone()]);
+    }
+
+    #[test]
+    fn test_update_from_scored_picks_highest_scores() {
+        // GIVEN
+        let mut setup = Setup::new(3);
+        let scored = setup
+            .items
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(index, item)| (index as i64, item));
+
+        // WHEN
+        setup.view.update_from_scored(scored);
+
+        // THEN the 3 highest-scoring items were kept (display order is handled by `update`).
+        let mut names: Vec<String> = setup
+            .view
+            .items()
+            .map(|item| item.item.as_ref().unwrap().name.clone())
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["K", "L", "M"]);
+    }
+
+    #[test]
+    fn test_update_from_scored_breaks_ties_on_earlier_index() {
+        // GIVEN
+        let mut setup = Setup::new(2);
+        let scored = vec![(1, item("A")), (1, item("B")), (0, item("C"))].into_iter();
+
+        // WHEN
+        setup.view.update_from_scored(scored);
+
+        // THEN both tied items are kept ahead of the lower-scoring one, and the earlier
+        // of the two ("A") ranks first.
+        let names: Vec<String> = setup
+            .view
+            .items()
+            .map(|item| item.item.as_ref().unwrap().name.clone())
+            .collect();
+        assert_eq!(names, vec!["A", "B"]);
+    }
+
+    #[test]
+    fn test_page_down_scrolls_past_capacity() {
+        // GIVEN a 13-item result set with only 4 rows visible at a time
+        let mut setup = Setup::new(4);
+        setup.view.update(&setup.items); // selects "A" (index 0)
+
+        // WHEN paging down a full screen
+        setup.view.page_down();
+
+        // THEN the selection moved a full `capacity` further into the *entire* result
+        // set, scrolling the window rather than being capped at what was visible.
+        assert_eq!(setup.view.get_selected().item.as_ref().unwrap().name, "E");
+    }
+
+    #[test]
+    fn test_page_down_reverses_page_up() {
+        // GIVEN
+        let mut setup = Setup::new(4);
+        setup.view.update(&setup.items);
+        setup.view.page_down();
+        assert_eq!(setup.view.get_selected().item.as_ref().unwrap().name, "E");
+
+        // WHEN
+        setup.view.page_up();
+
+        // THEN a full screen of movement brings the selection back to where it was.
+        assert_eq!(setup.view.get_selected().item.as_ref().unwrap().name, "A");
+    }
+
+    #[test]
+    fn test_page_down_stops_at_bottom() {
+        // GIVEN
+        let mut setup = Setup::new(4);
+        setup.view.update(&setup.items);
+
+        // WHEN paging down repeatedly past the end of all 13 matches
+        setup.view.page_down();
+        setup.view.page_down();
+        setup.view.page_down();
+        setup.view.page_down();
+
+        // THEN the selection stops on the last match rather than overshooting.
+        assert_eq!(setup.view.len(), 4);
+        assert_eq!(setup.view.get_selected().item.as_ref().unwrap().name, "M");
+    }
+
+    #[test]
+    fn test_select_last_scrolls_final_item_to_the_bottom_beyond_capacity() {
+        // GIVEN a result set much larger than what fits on screen
+        let mut setup = Setup::new(4);
+        setup.view.update(&setup.items); // 13 matches, only 4 visible
+
+        // WHEN
+        setup.view.select_last();
+
+        // THEN the final match ("M") is selected, and the viewport scrolled so it's the
+        // bottom row rather than being unreachable past the `capacity`-bounded window.
+        assert_eq!(setup.view.len(), 4);
+        assert_eq!(setup.view.len_above(), 3);
+        assert_eq!(setup.view.get_selected().item.as_ref().unwrap().name, "M");
+    }
+
+    #[test]
+    fn test_select_first_then_select_last_round_trips() {
+        // GIVEN
+        let mut setup = Setup::new(4);
+        setup.view.update(&setup.items);
+        setup.view.select_last();
+
+        // WHEN
+        setup.view.select_first();
+
+        // THEN the very first match is selected again, with nothing above it.
+        assert_eq!(setup.view.len(), 4);
+        assert_eq!(setup.view.len_above(), 0);
+        assert_eq!(setup.view.get_selected().item.as_ref().unwrap().name, "A");
+    }
+
+    #[test]
+    fn test_page_down_after_single_step_uses_correct_absolute_index() {
+        // GIVEN a single step away from the top (previously desynced `selected_absolute`
+        // from the true position, because `update` and `set_window` disagreed on
+        // ordering)
+        let mut setup = Setup::new(4);
+        setup.view.update(&setup.items); // A
+        setup.view.down(); // B, true index 1
+
+        // WHEN
+        setup.view.page_down();
+
+        // THEN the full screen moved from B's true position (index 1), landing on "F"
+        // (index 5) — not "E" (index 4), which is what a desync from index 0 would give.
+        assert_eq!(setup.view.get_selected().item.as_ref().unwrap().name, "F");
+    }
+
+    #[test]
+    fn test_update_and_set_window_agree_on_ordering() {
+        // GIVEN a fresh `update` (top-down order: best match first)
+        let mut setup = Setup::new(4);
+        setup.view.update(&setup.items);
+        let fresh_order: Vec<String> = setup
+            .view
+            .items()
+            .map(|item| item.item.as_ref().unwrap().name.clone())
+            .collect();
+        assert_eq!(fresh_order, vec!["A", "B", "C", "D"]);
+
+        // WHEN paging away and back, which rebuilds the window via `set_window`
+        setup.view.page_down();
+        setup.view.page_up();
+
+        // THEN the visible order is unchanged — `update` and `set_window` agree on
+        // which row is "top", so the list doesn't flip orientation after paging.
+        let after_round_trip: Vec<String> = setup
+            .view
+            .items()
+            .map(|item| item.item.as_ref().unwrap().name.clone())
+            .collect();
+        assert_eq!(after_round_trip, fresh_order);
+    }
+
+    #[test]
+    fn test_page_up_stops_at_top() {
+        // GIVEN
+        let mut setup = Setup::new(4);
+        setup.view.update(&setup.items);
+        setup.view.select_last();
+
+        // WHEN paging up past the start
+        setup.view.page_up();
+        setup.view.page_up();
+        setup.view.page_up();
+        setup.view.page_up();
+
+        // THEN the selection stops on the first match rather than undershooting.
+        assert_eq!(setup.view.get_selected().item.as_ref().unwrap().name, "A");
         assert_eq!(setup.view.len_above(), 0);
     }
 }
@@ -0,0 +1,145 @@
+//! Pluggable scoring of candidates against a query, and the default fuzzy scorer.
+//!
+//! The `name` used to identify an `Item` is also what gets scored, so results can be
+//! sorted by score and the matched characters can be highlighted.
+
+/// The outcome of scoring a candidate: how well it matched, and where.
+pub struct Match {
+    pub score: i64,
+    /// Char offsets into the candidate that the query matched, in order.
+    pub positions: Vec<usize>,
+}
+
+/// Something that can score a candidate string against a query.
+///
+/// Implement this to swap in substring, prefix, or any other matching strategy in place
+/// of the default fuzzy scorer.
+pub trait Matcher {
+    /// Scores `candidate` against `query`, or returns `None` if it doesn't match at all.
+    fn score(&self, query: &str, candidate: &str) -> Option<Match>;
+}
+
+/// The default scorer: an fzf-style fuzzy match.
+///
+/// A candidate matches if every character of `query` appears in `candidate`, in order
+/// (not necessarily contiguous). Matching is case-insensitive unless `query` contains an
+/// uppercase letter ("smart case"). Score rewards, per matched character: a base point, a
+/// bonus for being adjacent to the previous match, and a bonus for landing on a word
+/// boundary (start of string, after a separator like `_`, `-`, `/` or space, or a
+/// camelCase hump).
+#[derive(Default)]
+pub struct FuzzyMatcher;
+
+impl Matcher for FuzzyMatcher {
+    fn score(&self, query: &str, candidate: &str) -> Option<Match> {
+        if query.is_empty() {
+            return Some(Match {
+                score: 0,
+                positions: Vec::new(),
+            });
+        }
+
+        let smart_case = query.chars().any(|c| c.is_uppercase());
+        let fold = |c: char| {
+            if smart_case {
+                c
+            } else {
+                c.to_lowercase().next().unwrap_or(c)
+            }
+        };
+
+        let query: Vec<char> = query.chars().map(fold).collect();
+        let original: Vec<char> = candidate.chars().collect();
+        let folded: Vec<char> = original.iter().copied().map(fold).collect();
+
+        let mut positions = Vec::with_capacity(query.len());
+        let mut score = 0i64;
+        let mut query_index = 0;
+        let mut last_match: Option<usize> = None;
+
+        for (index, &ch) in folded.iter().enumerate() {
+            if query_index >= query.len() {
+                break;
+            }
+            if ch != query[query_index] {
+                continue;
+            }
+
+            let mut bonus = 1;
+            if last_match == Some(index.wrapping_sub(1)) {
+                bonus += 2;
+            }
+            if is_word_boundary(&original, index) {
+                bonus += 2;
+            }
+
+            score += bonus;
+            positions.push(index);
+            last_match = Some(index);
+            query_index += 1;
+        }
+
+        if query_index < query.len() {
+            None
+        } else {
+            Some(Match { score, positions })
+        }
+    }
+}
+
+fn is_word_boundary(chars: &[char], index: usize) -> bool {
+    if index == 0 {
+        return true;
+    }
+    let previous = chars[index - 1];
+    if matches!(previous, '_' | '-' | '/' | ' ') {
+        return true;
+    }
+    let current = chars[index];
+    previous.is_lowercase() && current.is_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_in_order_subsequence() {
+        let m = FuzzyMatcher.score("fzr", "FuzzyFinder").unwrap();
+        assert_eq!(m.positions, vec![0, 2, 10]);
+    }
+
+    #[test]
+    fn rejects_out_of_order_query() {
+        assert!(FuzzyMatcher.score("zf", "fuzzy").is_none());
+    }
+
+    #[test]
+    fn is_case_insensitive_by_default() {
+        assert!(FuzzyMatcher.score("fuzzy", "FUZZY finder").is_some());
+    }
+
+    #[test]
+    fn smart_case_is_case_sensitive_with_uppercase_query() {
+        assert!(FuzzyMatcher.score("Fuzzy", "fuzzy finder").is_none());
+        assert!(FuzzyMatcher.score("Fuzzy", "Fuzzy finder").is_some());
+    }
+
+    #[test]
+    fn consecutive_matches_score_higher_than_scattered_ones() {
+        let consecutive = FuzzyMatcher.score("fuz", "fuzzy").unwrap();
+        let scattered = FuzzyMatcher.score("fzy", "fuzzy").unwrap();
+        assert!(consecutive.score > scattered.score);
+    }
+
+    #[test]
+    fn word_boundary_and_camel_case_hump_score_higher() {
+        let boundary = FuzzyMatcher.score("f", "my_file").unwrap();
+        let mid_word = FuzzyMatcher.score("i", "my_file").unwrap();
+        assert!(boundary.score > mid_word.score);
+
+        let hump = FuzzyMatcher.score("f", "myFile").unwrap();
+        let lower = FuzzyMatcher.score("y", "myFile").unwrap();
+        assert!(hump.score > lower.score);
+    }
+}
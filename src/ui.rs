@@ -0,0 +1,283 @@
+/// Terminal rendering and the input loop. Everything crossterm-specific lives here so
+/// `List` and `Item` stay free of anything UI-specific.
+use std::io::{stdout, Write};
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::time::Duration;
+
+use anyhow::Result;
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    execute, queue, style,
+    terminal::{self, ClearType},
+};
+
+use crate::item::Item;
+use crate::list::List;
+use crate::matcher::Matcher;
+
+/// How long to wait for a key press before looping round again to check for newly
+/// arrived items while streaming.
+const STREAMING_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+enum Outcome<T> {
+    Cancelled,
+    Selected(T),
+    Marked(Vec<T>),
+}
+
+pub(crate) fn run<T: Clone>(
+    items: Vec<Item<T>>,
+    lines_to_show: i8,
+    matcher: &dyn Matcher,
+) -> Result<Option<T>> {
+    match interact(items, lines_to_show, false, matcher)? {
+        Outcome::Cancelled => Ok(None),
+        Outcome::Selected(item) => Ok(item.item),
+        Outcome::Marked(_) => unreachable!("single-select mode never produces marks"),
+    }
+}
+
+pub(crate) fn run_multi<T: Clone>(
+    items: Vec<Item<T>>,
+    lines_to_show: i8,
+    matcher: &dyn Matcher,
+) -> Result<Vec<T>> {
+    match interact(items, lines_to_show, true, matcher)? {
+        Outcome::Cancelled => Ok(Vec::new()),
+        Outcome::Selected(item) => Ok(item.item.into_iter().collect()),
+        Outcome::Marked(marked) => Ok(marked.into_iter().filter_map(|item| item.item).collect()),
+    }
+}
+
+fn interact<T: Clone>(
+    items: Vec<Item<T>>,
+    lines_to_show: i8,
+    multi_select: bool,
+    matcher: &dyn Matcher,
+) -> Result<Outcome<Item<T>>> {
+    let mut query = String::new();
+    let mut list = List::<Item<T>>::new(lines_to_show as usize);
+    filter_and_update(&items, &query, matcher, &mut list);
+
+    terminal::enable_raw_mode()?;
+    execute!(stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+
+    let outcome = loop {
+        render(&query, &list, multi_select, "")?;
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind == KeyEventKind::Release {
+            continue;
+        }
+
+        match key.code {
+            KeyCode::Esc => break Outcome::Cancelled,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                break Outcome::Cancelled
+            }
+            KeyCode::Enter if list.is_empty() => break Outcome::Cancelled,
+            KeyCode::Enter => {
+                let marked: Vec<_> = list.marked().collect();
+                if multi_select && !marked.is_empty() {
+                    break Outcome::Marked(marked);
+                }
+                break Outcome::Selected(list.get_selected().clone());
+            }
+            KeyCode::Up => list.up(),
+            KeyCode::Down => list.down(),
+            KeyCode::PageUp => list.page_up(),
+            KeyCode::PageDown => list.page_down(),
+            KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                list.page_up()
+            }
+            KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                list.page_down()
+            }
+            KeyCode::Home => list.select_first(),
+            KeyCode::End => list.select_last(),
+            KeyCode::Tab if multi_select => list.toggle_mark(),
+            KeyCode::Backspace => {
+                query.pop();
+                filter_and_update(&items, &query, matcher, &mut list);
+            }
+            KeyCode::Char(c) => {
+                query.push(c);
+                filter_and_update(&items, &query, matcher, &mut list);
+            }
+            _ => {}
+        }
+    };
+
+    execute!(stdout(), cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    Ok(outcome)
+}
+
+pub(crate) fn run_streaming<T: Clone>(
+    rx: Receiver<Item<T>>,
+    lines_to_show: i8,
+    matcher: &dyn Matcher,
+) -> Result<Option<T>> {
+    match interact_streaming(rx, lines_to_show, matcher)? {
+        Outcome::Cancelled => Ok(None),
+        Outcome::Selected(item) => Ok(item.item),
+        Outcome::Marked(_) => unreachable!("streaming mode never produces marks"),
+    }
+}
+
+/// Like `interact`, but `pool` grows as items arrive on `rx` instead of being fixed up
+/// front. The current query is re-run against the pool each time it grows, and a
+/// loading indicator is shown until `rx` disconnects.
+fn interact_streaming<T: Clone>(
+    rx: Receiver<Item<T>>,
+    lines_to_show: i8,
+    matcher: &dyn Matcher,
+) -> Result<Outcome<Item<T>>> {
+    let mut query = String::new();
+    let mut pool: Vec<Item<T>> = Vec::new();
+    let mut loading = true;
+    let mut list = List::<Item<T>>::new(lines_to_show as usize);
+
+    terminal::enable_raw_mode()?;
+    execute!(stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+
+    let outcome = loop {
+        if loading {
+            loading = drain_into(&rx, &mut pool);
+            filter_and_update(&pool, &query, matcher, &mut list);
+        }
+
+        let status = if loading { "(loading...)" } else { "" };
+        render(&query, &list, false, status)?;
+
+        if !event::poll(STREAMING_POLL_INTERVAL)? {
+            continue;
+        }
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind == KeyEventKind::Release {
+            continue;
+        }
+
+        match key.code {
+            KeyCode::Esc => break Outcome::Cancelled,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                break Outcome::Cancelled
+            }
+            KeyCode::Enter if list.is_empty() => break Outcome::Cancelled,
+            KeyCode::Enter => break Outcome::Selected(list.get_selected().clone()),
+            KeyCode::Up => list.up(),
+            KeyCode::Down => list.down(),
+            KeyCode::PageUp => list.page_up(),
+            KeyCode::PageDown => list.page_down(),
+            KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                list.page_up()
+            }
+            KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                list.page_down()
+            }
+            KeyCode::Home => list.select_first(),
+            KeyCode::End => list.select_last(),
+            KeyCode::Backspace => {
+                query.pop();
+                filter_and_update(&pool, &query, matcher, &mut list);
+            }
+            KeyCode::Char(c) => {
+                query.push(c);
+                filter_and_update(&pool, &query, matcher, &mut list);
+            }
+            _ => {}
+        }
+    };
+
+    execute!(stdout(), cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    Ok(outcome)
+}
+
+/// Drains every item currently buffered on `rx` into `pool` without blocking. Returns
+/// whether the sender is still connected (i.e. whether we're still loading).
+fn drain_into<T: Clone>(rx: &Receiver<Item<T>>, pool: &mut Vec<Item<T>>) -> bool {
+    loop {
+        match rx.try_recv() {
+            Ok(item) => pool.push(item),
+            Err(TryRecvError::Empty) => return true,
+            Err(TryRecvError::Disconnected) => return false,
+        }
+    }
+}
+
+/// Scores every item against `query` with `matcher`, discarding non-matches, and hands
+/// the scored survivors to `List::update_from_scored` to pick the visible top rows
+/// without sorting the whole candidate pool.
+fn filter_and_update<T: Clone>(
+    items: &[Item<T>],
+    query: &str,
+    matcher: &dyn Matcher,
+    list: &mut List<Item<T>>,
+) {
+    let scored = items.iter().filter_map(|item| {
+        let m = matcher.score(query, &item.name)?;
+        let mut item = item.clone();
+        item.match_positions = m.positions;
+        Some((m.score, item))
+    });
+    list.update_from_scored(scored);
+}
+
+fn render<T: Clone>(
+    query: &str,
+    list: &List<Item<T>>,
+    multi_select: bool,
+    status: &str,
+) -> Result<()> {
+    let mut out = stdout();
+    queue!(out, terminal::Clear(ClearType::All), cursor::MoveTo(0, 0))?;
+    write!(out, "> {query}")?;
+    if !status.is_empty() {
+        write!(out, "  {status}")?;
+    }
+    for (row, (is_selected, is_marked, item)) in list.tagged_marked_iter().enumerate() {
+        queue!(out, cursor::MoveTo(0, (row + 1) as u16))?;
+        let caret = if is_selected { ">" } else { " " };
+        let marker = if multi_select {
+            if is_marked {
+                "*"
+            } else {
+                " "
+            }
+        } else {
+            ""
+        };
+        write!(out, "{caret}{marker} ")?;
+        render_highlighted(&mut out, &item.name, &item.match_positions)?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+/// Writes `name`, colouring the characters at `matched` (as returned by a `Matcher`) to
+/// show the user why this item matched their query.
+fn render_highlighted(
+    out: &mut impl Write,
+    name: &str,
+    matched: &[usize],
+) -> Result<()> {
+    for (index, ch) in name.chars().enumerate() {
+        if matched.contains(&index) {
+            queue!(
+                out,
+                style::SetForegroundColor(style::Color::Green),
+                style::Print(ch),
+                style::ResetColor
+            )?;
+        } else {
+            queue!(out, style::Print(ch))?;
+        }
+    }
+    Ok(())
+}